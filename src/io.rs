@@ -0,0 +1,131 @@
+use std::io;
+
+use crate::ring_buffer::{
+  RingBufferConsumer, RingBufferConsumerRegion, RingBufferProducer, RingBufferProducerRegion,
+};
+use crate::{Consumer, ConsumerRegion, Producer};
+
+impl io::Write for RingBufferProducer<u8> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.region().write(buf)
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+impl io::Write for RingBufferProducerRegion<'_, u8> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    let (s1, s2) = self.write_slices();
+
+    let n1 = s1.len().min(buf.len());
+    s1[..n1].copy_from_slice(&buf[..n1]);
+
+    let rest = &buf[n1..];
+    let n2 = s2.len().min(rest.len());
+    s2[..n2].copy_from_slice(&rest[..n2]);
+
+    let written = n1 + n2;
+    self.advance(written);
+
+    // `Write::write` returning `Ok(0)` means "this writer will never accept
+    // any more bytes" (e.g. a closed pipe), which isn't true here — the
+    // buffer is just momentarily full, and a consumer reading from the
+    // other end will free up slots. Report `WouldBlock` instead so callers
+    // like `io::copy` don't mistake "full right now" for "done".
+    if written == 0 && !buf.is_empty() {
+      return Err(io::ErrorKind::WouldBlock.into());
+    }
+    Ok(written)
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+impl io::Read for RingBufferConsumer<u8> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self.region().read(buf)
+  }
+}
+
+impl io::Read for RingBufferConsumerRegion<'_, u8> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let (s1, s2) = self.as_slices();
+
+    let n1 = s1.len().min(buf.len());
+    buf[..n1].copy_from_slice(&s1[..n1]);
+
+    let n2 = s2.len().min(buf.len() - n1);
+    buf[n1..n1 + n2].copy_from_slice(&s2[..n2]);
+
+    let read = n1 + n2;
+    self.advance(read);
+
+    // `Read::read` returning `Ok(0)` means EOF: the stream will never
+    // produce another byte. That isn't true here — the buffer is just
+    // momentarily empty, and the producer may still be running — so report
+    // `WouldBlock` instead, or `io::copy` would stop early the first time
+    // it outpaces the producer.
+    if read == 0 && !buf.is_empty() {
+      return Err(io::ErrorKind::WouldBlock.into());
+    }
+    Ok(read)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ring_buffer::RingBuffer;
+  use std::io::{Read, Write};
+
+  #[test]
+  fn producer_write_and_consumer_read() {
+    let (mut consumer, mut producer) = RingBuffer::<u8>::new(4);
+
+    assert_eq!(producer.write(&[1, 2, 3, 4, 5]).unwrap(), 4);
+
+    let mut buf = [0u8; 4];
+    assert_eq!(consumer.read(&mut buf).unwrap(), 4);
+    assert_eq!(buf, [1, 2, 3, 4]);
+  }
+
+  #[test]
+  fn producer_write_wraps_around() {
+    let (mut consumer, mut producer) = RingBuffer::<u8>::new(4);
+
+    assert_eq!(producer.write(&[1, 2, 3]).unwrap(), 3);
+
+    let mut buf = [0u8; 2];
+    assert_eq!(consumer.read(&mut buf).unwrap(), 2);
+    assert_eq!(buf, [1, 2]);
+
+    assert_eq!(producer.write(&[4, 5, 6]).unwrap(), 3);
+
+    let mut buf = [0u8; 3];
+    assert_eq!(consumer.read(&mut buf).unwrap(), 3);
+    assert_eq!(buf, [3, 4, 5]);
+  }
+
+  #[test]
+  fn consumer_read_reports_would_block_instead_of_eof_on_empty_buffer() {
+    let (mut consumer, _producer) = RingBuffer::<u8>::new(4);
+
+    let mut buf = [0u8; 4];
+    let err = consumer.read(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+  }
+
+  #[test]
+  fn producer_write_reports_would_block_instead_of_eof_on_full_buffer() {
+    let (_consumer, mut producer) = RingBuffer::<u8>::new(4);
+
+    assert_eq!(producer.write(&[1, 2, 3, 4]).unwrap(), 4);
+
+    let err = producer.write(&[5]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+  }
+}
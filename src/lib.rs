@@ -1,5 +1,15 @@
 mod ring_buffer;
-use ring_buffer::{RingBufferConsumerRegion, RingBufferProducerRegion};
+mod storage;
+#[cfg(feature = "std")]
+mod io;
+
+use std::mem::MaybeUninit;
+
+use ring_buffer::{
+  RingBufferConsumerPostponedRegion, RingBufferConsumerRegion, RingBufferProducerPostponedRegion,
+  RingBufferProducerRegion,
+};
+pub use storage::Storage;
 
 #[derive(Debug, PartialEq)]
 pub struct PopError;
@@ -7,18 +17,26 @@ pub struct PopError;
 #[derive(Debug, PartialEq)]
 pub struct PushError;
 
-pub trait Consumer<T> {
+// `S` defaults to the heap-backed storage `RingBuffer::new` uses, so
+// `impl Consumer<T>` keeps working unchanged for callers who don't care
+// where the slots live. Implementations backed by inline array or
+// borrowed-slice storage (see the `storage` module) are `Consumer<T, S>`
+// for their own `S`.
+pub trait Consumer<T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
   // Number of slots ready for consuming
   fn slot_count(&self) -> usize;
 
   // Simple sequential interface to pop slots and sync the internal cursor
-  fn pop(&mut self) -> Result<T, PopError>
-  where
-    T: Copy;
+  fn pop(&mut self) -> Result<T, PopError>;
 
   // Advanced interface to take an snapshot of the slots
   // that are currently available for consumption
-  fn region(&mut self) -> RingBufferConsumerRegion<T>;
+  fn region(&mut self) -> RingBufferConsumerRegion<'_, T, S>;
+
+  // Transactional variant of `region()`: nothing is synced to the shared
+  // cursor unless the returned region is explicitly committed, so
+  // speculative reads can be abandoned in full just by dropping it.
+  fn postponed_region(&mut self) -> RingBufferConsumerPostponedRegion<'_, T, S>;
 }
 
 // This represents a frozen view of the Consumer
@@ -29,9 +47,7 @@ pub trait ConsumerRegion<'a, T> {
   fn slot_count(&self) -> usize;
 
   // Provides next slot and advances the cursor one position within the region
-  fn pop(&mut self) -> Result<T, PopError>
-  where
-    T: Copy;
+  fn pop(&mut self) -> Result<T, PopError>;
 
   // Provides a low level interface to the underlying buffer slices
   fn as_slices(&self) -> (&[T], &[T]);
@@ -43,18 +59,21 @@ pub trait ConsumerRegion<'a, T> {
   fn advance(&mut self, n: usize);
 }
 
-pub trait Producer<T> {
+pub trait Producer<T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
   // Number of slots ready for producing
   fn slot_count(&self) -> usize;
 
   // Simple sequential interface to push slots and sync the internal cursor
-  fn push(&mut self, value: T) -> Result<(), PushError>
-  where
-    T: Copy;
+  fn push(&mut self, value: T) -> Result<(), PushError>;
 
   // Advanced interface to take an snapshot of the slots
   // that are currently available for producing
-  fn region(&mut self) -> RingBufferProducerRegion<T>;
+  fn region(&mut self) -> RingBufferProducerRegion<'_, T, S>;
+
+  // Transactional variant of `region()`: nothing is synced to the shared
+  // cursor unless the returned region is explicitly committed, so a batch
+  // of speculative writes can be abandoned in full just by dropping it.
+  fn postponed_region(&mut self) -> RingBufferProducerPostponedRegion<'_, T, S>;
 }
 
 // This represents a frozen view of the Consumer
@@ -65,9 +84,7 @@ pub trait ProducerRegion<'a, T> {
   fn slot_count(&self) -> usize;
 
   // Pushes one value to the region
-  fn push(&mut self, value: T) -> Result<(), PushError>
-  where
-    T: Copy;
+  fn push(&mut self, value: T) -> Result<(), PushError>;
 }
 
 #[cfg(test)]
@@ -85,6 +102,36 @@ mod tests {
     assert_eq!(4, producer.slot_count());
   }
 
+  #[test]
+  fn ring_buffer_push_and_pop_non_copy() {
+    let (mut consumer, mut producer) = RingBuffer::<String>::new(2);
+
+    assert_eq!(producer.push(String::from("a")), Ok(()));
+    assert_eq!(producer.push(String::from("b")), Ok(()));
+    assert_eq!(producer.push(String::from("c")), Err(PushError));
+
+    assert_eq!(consumer.pop(), Ok(String::from("a")));
+    assert_eq!(consumer.pop(), Ok(String::from("b")));
+    assert_eq!(consumer.pop(), Err(PopError));
+  }
+
+  #[test]
+  fn ring_buffer_drops_owned_values_left_in_the_buffer() {
+    use std::rc::Rc;
+
+    let dropped = Rc::new(());
+    let (_consumer, mut producer) = RingBuffer::<Rc<()>>::new(4);
+
+    assert_eq!(producer.push(Rc::clone(&dropped)), Ok(()));
+    assert_eq!(producer.push(Rc::clone(&dropped)), Ok(()));
+    assert_eq!(Rc::strong_count(&dropped), 3);
+
+    drop(producer);
+    drop(_consumer);
+
+    assert_eq!(Rc::strong_count(&dropped), 1);
+  }
+
   #[test]
   fn ring_buffer_push_and_pop() {
     let (mut consumer, mut producer) = RingBuffer::<CopyType>::new(2);
@@ -204,4 +251,161 @@ mod tests {
 
     assert_eq!(consumer.slot_count(), 2);
   }
+
+  #[test]
+  fn ring_buffer_with_array_storage() {
+    let storage = RingBuffer::<CopyType, [std::mem::MaybeUninit<CopyType>; 3]>::uninit_array();
+    let (mut consumer, mut producer) = RingBuffer::with_storage(storage);
+
+    assert_eq!(producer.push(1), Ok(()));
+    assert_eq!(producer.push(2), Ok(()));
+    assert_eq!(producer.push(3), Ok(()));
+    assert_eq!(producer.push(4), Err(PushError));
+
+    assert_eq!(consumer.pop(), Ok(1));
+    assert_eq!(consumer.pop(), Ok(2));
+    assert_eq!(consumer.pop(), Ok(3));
+    assert_eq!(consumer.pop(), Err(PopError));
+  }
+
+  #[test]
+  fn ring_buffer_with_borrowed_slice_storage() {
+    let mut slots = [
+      std::mem::MaybeUninit::<CopyType>::uninit(),
+      std::mem::MaybeUninit::uninit(),
+      std::mem::MaybeUninit::uninit(),
+    ];
+    let (mut consumer, mut producer) = RingBuffer::with_storage(&mut slots[..]);
+
+    assert_eq!(producer.push(1), Ok(()));
+    assert_eq!(producer.push(2), Ok(()));
+    assert_eq!(producer.push(3), Ok(()));
+    assert_eq!(producer.push(4), Err(PushError));
+
+    assert_eq!(consumer.pop(), Ok(1));
+    assert_eq!(consumer.pop(), Ok(2));
+    assert_eq!(consumer.pop(), Ok(3));
+    assert_eq!(consumer.pop(), Err(PopError));
+  }
+
+  #[test]
+  fn ring_buffer_reunite() {
+    let (mut consumer, mut producer) = RingBuffer::<CopyType>::new(4);
+
+    assert_eq!(producer.push(1), Ok(()));
+    assert_eq!(producer.push(2), Ok(()));
+    assert_eq!(consumer.pop(), Ok(1));
+
+    let buffer = RingBuffer::reunite(consumer, producer).unwrap_or_else(|_| panic!("same buffer"));
+    let (storage, read, write) = buffer.into_raw_parts();
+
+    assert_eq!(read, 1);
+    assert_eq!(write, 2);
+
+    let (mut consumer, producer) = unsafe { RingBuffer::from_raw_parts(storage, read, write) };
+
+    assert_eq!(consumer.slot_count(), 1);
+    assert_eq!(consumer.pop(), Ok(2));
+    assert_eq!(producer.slot_count(), 4);
+  }
+
+  #[test]
+  fn ring_buffer_reunite_fails_across_different_buffers() {
+    let (consumer, _producer) = RingBuffer::<CopyType>::new(4);
+    let (_other_consumer, producer) = RingBuffer::<CopyType>::new(4);
+
+    assert!(RingBuffer::reunite(consumer, producer).is_err());
+  }
+
+  #[test]
+  fn producer_postponed_region_rolls_back_on_drop() {
+    let (consumer, mut producer) = RingBuffer::<CopyType>::new(4);
+
+    let mut region = producer.postponed_region();
+    assert_eq!(region.push(1), Ok(()));
+    assert_eq!(region.push(2), Ok(()));
+    drop(region);
+
+    assert_eq!(producer.slot_count(), 4);
+    assert_eq!(consumer.slot_count(), 0);
+  }
+
+  #[test]
+  fn producer_postponed_region_commit() {
+    let (mut consumer, mut producer) = RingBuffer::<CopyType>::new(4);
+
+    let mut region = producer.postponed_region();
+    assert_eq!(region.push(1), Ok(()));
+    assert_eq!(region.push(2), Ok(()));
+    region.commit();
+
+    assert_eq!(producer.slot_count(), 2);
+    assert_eq!(consumer.pop(), Ok(1));
+    assert_eq!(consumer.pop(), Ok(2));
+  }
+
+  #[test]
+  fn producer_postponed_region_commit_n_drops_the_rest() {
+    use std::rc::Rc;
+
+    let dropped = Rc::new(());
+    let (mut consumer, mut producer) = RingBuffer::<Rc<()>>::new(4);
+
+    let mut region = producer.postponed_region();
+    assert_eq!(region.push(Rc::clone(&dropped)), Ok(()));
+    assert_eq!(region.push(Rc::clone(&dropped)), Ok(()));
+    assert_eq!(Rc::strong_count(&dropped), 3);
+
+    region.commit_n(1);
+    assert_eq!(Rc::strong_count(&dropped), 2);
+
+    assert_eq!(consumer.pop().map(|rc| Rc::strong_count(&rc)), Ok(2));
+  }
+
+  #[test]
+  fn consumer_postponed_region_rolls_back_on_drop() {
+    let (mut consumer, mut producer) = RingBuffer::<CopyType>::new(4);
+
+    assert_eq!(producer.push(1), Ok(()));
+    assert_eq!(producer.push(2), Ok(()));
+
+    let region = consumer.postponed_region();
+    assert_eq!(region.as_slices(), (&[1, 2][..], &[][..]));
+    drop(region);
+
+    assert_eq!(consumer.slot_count(), 2);
+    assert_eq!(consumer.pop(), Ok(1));
+  }
+
+  #[test]
+  fn consumer_postponed_region_commit_n() {
+    let (mut consumer, mut producer) = RingBuffer::<CopyType>::new(4);
+
+    assert_eq!(producer.push(1), Ok(()));
+    assert_eq!(producer.push(2), Ok(()));
+
+    let region = consumer.postponed_region();
+    region.commit_n(1);
+
+    assert_eq!(consumer.slot_count(), 1);
+    assert_eq!(consumer.pop(), Ok(2));
+  }
+
+  #[test]
+  fn consumer_postponed_region_commit_n_drops_the_committed() {
+    use std::rc::Rc;
+
+    let dropped = Rc::new(());
+    let (mut consumer, mut producer) = RingBuffer::<Rc<()>>::new(4);
+
+    assert_eq!(producer.push(Rc::clone(&dropped)), Ok(()));
+    assert_eq!(producer.push(Rc::clone(&dropped)), Ok(()));
+    assert_eq!(Rc::strong_count(&dropped), 3);
+
+    let region = consumer.postponed_region();
+    region.commit_n(1);
+    assert_eq!(Rc::strong_count(&dropped), 2);
+
+    assert_eq!(consumer.pop().map(|rc| Rc::strong_count(&rc)), Ok(2));
+  }
 }
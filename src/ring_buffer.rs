@@ -1,171 +1,422 @@
 use std::cell::UnsafeCell;
-use std::rc::Rc;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
+use crate::storage::Storage;
 use crate::{Consumer, ConsumerRegion, PopError, Producer, ProducerRegion, PushError};
 
-pub struct RingBuffer<T> {
-  buffer: Box<[T]>,
-  read_index: usize,
-  size: usize,
+// The read cursor is only ever written by the consumer and the write cursor
+// only ever written by the producer, so the two halves can be moved to
+// different threads and communicate without a lock. Both cursors count
+// slots produced/consumed since the buffer was created rather than being
+// wrapped to the capacity, so `write.wrapping_sub(read)` gives the current
+// length even once either index wraps around `usize::MAX`, and there is no
+// need for a shared `size` field to disambiguate full from empty.
+//
+// `S` abstracts over where the slots live (heap box, inline array, borrowed
+// slice, ...); see the `storage` module. Slots are `MaybeUninit<T>` because
+// only the `read..write` range is ever initialized; `push`/`pop` move
+// values in and out with `ptr::write`/`ptr::read` instead of requiring
+// `T: Copy`, and `Drop` walks the live range to run destructors for
+// whatever was never consumed.
+pub struct RingBuffer<T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: UnsafeCell<S>,
+  capacity: usize,
+  read: AtomicUsize,
+  write: AtomicUsize,
+  // `T` only appears through the `S: Storage<T>` bound, never in a field, so
+  // this tells the compiler (and variance) that the buffer still owns `T`s.
+  _marker: std::marker::PhantomData<T>,
 }
 
-impl<T> RingBuffer<T> {
-  pub fn new(capacity: usize) -> (RingBufferConsumer<T>, RingBufferProducer<T>) {
-    let mut v = Vec::with_capacity(capacity);
-    unsafe { v.set_len(capacity) }
+// SAFETY: at most one `RingBufferConsumer` ever touches the slots in
+// `read..write` and at most one `RingBufferProducer` ever touches the slots
+// in `write..read`; the Acquire/Release pair on `read`/`write` establishes
+// the happens-before edge between them, so sharing the buffer across
+// threads is sound as long as `T` and the storage itself are `Send`.
+unsafe impl<T: Send, S: Storage<T> + Send> Send for RingBuffer<T, S> {}
+unsafe impl<T: Send, S: Storage<T> + Send> Sync for RingBuffer<T, S> {}
+
+impl<T> RingBuffer<T, Box<[MaybeUninit<T>]>> {
+  pub fn new(capacity: usize) -> (
+    RingBufferConsumer<T, Box<[MaybeUninit<T>]>>,
+    RingBufferProducer<T, Box<[MaybeUninit<T>]>>,
+  ) {
+    let slots: Vec<MaybeUninit<T>> = (0..capacity).map(|_| MaybeUninit::uninit()).collect();
+    RingBuffer::with_storage(slots.into_boxed_slice())
+  }
+}
+
+impl<T, const N: usize> RingBuffer<T, [MaybeUninit<T>; N]> {
+  // A `const fn` way to build an array of `N` uninitialized slots, usable in
+  // a `static` or on the stack. Pair with `RingBuffer::with_storage` to keep
+  // the slots inline in the buffer instead of in a separate boxed slice:
+  //
+  // ```ignore
+  // static mut STORAGE: [MaybeUninit<u8>; 64] = RingBuffer::uninit_array();
+  // let (consumer, producer) = RingBuffer::with_storage(unsafe { STORAGE });
+  // ```
+  //
+  // `with_storage` moves (in the example, copies) the array into the shared
+  // state, which it always allocates with `Arc`, so the buffer does not stay
+  // in the static and a heap allocator and `std` are still required.
+  pub const fn uninit_array() -> [MaybeUninit<T>; N] {
+    // SAFETY: an array of `MaybeUninit<T>` is valid in any bit pattern,
+    // including fully uninitialized, for any `N`.
+    unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
+  }
+}
 
-    let buffer = Rc::new(UnsafeCell::new(RingBuffer {
-      buffer: v.into_boxed_slice(),
-      read_index: 0,
-      size: 0,
-    }));
+impl<T, S: Storage<T>> RingBuffer<T, S> {
+  // Builds a consumer/producer pair on top of caller-provided storage,
+  // whatever it is backed by. `RingBuffer::new` is just this plus a
+  // `Box<[MaybeUninit<T>]>` of the requested capacity.
+  pub fn with_storage(storage: S) -> (RingBufferConsumer<T, S>, RingBufferProducer<T, S>) {
+    Self::split(storage, 0, 0)
+  }
+
+  // # Safety
+  //
+  // `read` and `write` must describe a state that's actually valid for
+  // `storage`: `write.wrapping_sub(read) <= storage.storage_len()`, and
+  // every slot in the live `read..write` range (mod capacity) must already
+  // be initialized, since the reconstructed buffer's `Drop`, `pop`, and
+  // region reads all treat that range as live `T` values.
+  pub unsafe fn from_raw_parts(
+    storage: S,
+    read: usize,
+    write: usize,
+  ) -> (RingBufferConsumer<T, S>, RingBufferProducer<T, S>) {
+    Self::split(storage, read, write)
+  }
+
+  fn split(
+    storage: S,
+    read: usize,
+    write: usize,
+  ) -> (RingBufferConsumer<T, S>, RingBufferProducer<T, S>) {
+    let capacity = storage.storage_len();
+
+    let buffer = Arc::new(RingBuffer {
+      buffer: UnsafeCell::new(storage),
+      capacity,
+      read: AtomicUsize::new(read),
+      write: AtomicUsize::new(write),
+      _marker: std::marker::PhantomData,
+    });
 
     let consumer = RingBufferConsumer {
-      buffer: Rc::clone(&buffer),
+      buffer: Arc::clone(&buffer),
     };
     let producer = RingBufferProducer { buffer };
 
     (consumer, producer)
   }
 
+  // Recombines a consumer/producer pair that came from the same buffer back
+  // into the owning `RingBuffer`, so the caller can reclaim the storage (see
+  // `into_raw_parts`) or just drop it as a unit. Fails, handing both halves
+  // back unchanged, if they don't share the same allocation or if something
+  // else (e.g. a still-live region) is also holding a reference to it.
+  pub fn reunite(
+    consumer: RingBufferConsumer<T, S>,
+    producer: RingBufferProducer<T, S>,
+  ) -> Result<RingBuffer<T, S>, (RingBufferConsumer<T, S>, RingBufferProducer<T, S>)> {
+    if !Arc::ptr_eq(&consumer.buffer, &producer.buffer) {
+      return Err((consumer, producer));
+    }
+
+    let RingBufferConsumer {
+      buffer: consumer_buffer,
+    } = consumer;
+    let RingBufferProducer {
+      buffer: producer_buffer,
+    } = producer;
+    drop(consumer_buffer);
+
+    Arc::try_unwrap(producer_buffer).map_err(|buffer| {
+      let consumer = RingBufferConsumer {
+        buffer: Arc::clone(&buffer),
+      };
+      let producer = RingBufferProducer { buffer };
+      (consumer, producer)
+    })
+  }
+
+  // The mirror of `with_storage`/`from_raw_parts`: hands the storage back
+  // along with the cursor positions needed to reconstruct an equivalent
+  // buffer later, without running `Drop` (and thus without dropping
+  // whatever elements are still live in `read..write`) on the way out.
+  pub fn into_raw_parts(self) -> (S, usize, usize) {
+    let mut this = std::mem::ManuallyDrop::new(self);
+    let read = *this.read.get_mut();
+    let write = *this.write.get_mut();
+    // SAFETY: `this` is a `ManuallyDrop`, so `RingBuffer`'s destructor (which
+    // would drop any elements still live in `read..write`) never runs over
+    // the storage we're about to move out of it; ownership of the storage,
+    // and of those elements, passes to the caller through the return value.
+    let storage = unsafe { ptr::read(this.buffer.get_mut()) };
+    (storage, read, write)
+  }
+
+  fn buffer_ptr(&self) -> *mut MaybeUninit<T> {
+    unsafe { (*self.buffer.get()).storage_mut_ptr() }
+  }
+
   pub fn read_slots(&self) -> usize {
-    self.size
+    let write = self.write.load(Ordering::Acquire);
+    let read = self.read.load(Ordering::Acquire);
+    write.wrapping_sub(read)
   }
 
   fn write_slots(&self) -> usize {
-    self.buffer.len() - self.size
+    self.capacity - self.read_slots()
   }
 
-  fn pop(&mut self) -> Result<T, PopError>
-  where
-    T: Copy,
-  {
-    if self.size > 0 {
-      let slot = self.buffer[self.read_index];
-      self.read_index = (self.read_index + 1) % self.buffer.len();
-      self.size -= 1;
+  fn pop(&self) -> Result<T, PopError> {
+    let read = self.read.load(Ordering::Relaxed);
+    let write = self.write.load(Ordering::Acquire);
+    if write.wrapping_sub(read) > 0 {
+      let idx = read % self.capacity;
+      let slot = unsafe { self.buffer_ptr().add(idx).read().assume_init() };
+      self.read.store(read.wrapping_add(1), Ordering::Release);
       Ok(slot)
     } else {
       Err(PopError)
     }
   }
 
-  fn push(&mut self, slot: T) -> Result<(), PushError> {
-    if self.size < self.buffer.len() {
-      self.buffer[self.write_index()] = slot;
-      self.size += 1;
+  fn push(&self, value: T) -> Result<(), PushError> {
+    let write = self.write.load(Ordering::Relaxed);
+    let read = self.read.load(Ordering::Acquire);
+    if write.wrapping_sub(read) < self.capacity {
+      let idx = write % self.capacity;
+      unsafe { self.buffer_ptr().add(idx).write(MaybeUninit::new(value)) };
+      self.write.store(write.wrapping_add(1), Ordering::Release);
       Ok(())
     } else {
       Err(PushError)
     }
   }
 
+  // Read-only, so it's sound for any `T`: the range is guaranteed
+  // initialized and nothing is moved out from behind the shared reference.
   fn read_slices(&self) -> (&[T], &[T]) {
-    let capacity = self.buffer.len();
-    let slice = &self.buffer;
-    let write_index = self.write_index();
-    if write_index > self.read_index || (write_index == self.read_index && self.size == 0) {
-      let range = self.read_index..write_index;
-      (&slice[range], &[])
-    } else {
-      let range1 = self.read_index..capacity;
-      let range2 = 0..write_index;
-      println!("r1: {:?}, r2: {:?}", range1, range2);
-      (&slice[range1], &slice[range2])
+    let read = self.read.load(Ordering::Relaxed);
+    let write = self.write.load(Ordering::Acquire);
+    let len = write.wrapping_sub(read);
+    let start = read % self.capacity;
+    let ptr = self.buffer_ptr() as *const T;
+    unsafe {
+      if start + len <= self.capacity {
+        (std::slice::from_raw_parts(ptr.add(start), len), &[])
+      } else {
+        let first_len = self.capacity - start;
+        (
+          std::slice::from_raw_parts(ptr.add(start), first_len),
+          std::slice::from_raw_parts(ptr, len - first_len),
+        )
+      }
     }
   }
 
-  fn write_slices(&mut self) -> (&mut [T], &mut [T]) {
-    let write_index = self.write_index();
-    let slice = self.buffer.as_mut();
-    if self.read_index >= write_index || (write_index == self.read_index && self.size == 0) {
-      let range = write_index..self.read_index;
-      (&mut slice[range], &mut [])
-    } else {
-      let (s2, s1) = slice.split_at_mut(write_index);
-      (s1, &mut s2[0..self.read_index])
+  // The write range is not yet initialized, so it's handed out as
+  // `MaybeUninit<T>` and filled in slot-by-slot with `MaybeUninit::write`
+  // (see `RingBufferProducerRegion::push`), which is sound for any `T`.
+  //
+  // SAFETY: materializing `&mut` slices from `&self` is sound only because
+  // at most one `RingBufferProducer`/region ever calls this for a given
+  // buffer at a time (the single-producer discipline `Send`/`Sync` above
+  // relies on), and the consumer side never touches the `write..read`
+  // range these slices cover.
+  #[allow(clippy::mut_from_ref)]
+  fn write_slices(&self) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+    let read = self.read.load(Ordering::Acquire);
+    let write = self.write.load(Ordering::Relaxed);
+    let len = self.capacity - write.wrapping_sub(read);
+    let start = write % self.capacity;
+    let ptr = self.buffer_ptr();
+    unsafe {
+      if start + len <= self.capacity {
+        (std::slice::from_raw_parts_mut(ptr.add(start), len), &mut [])
+      } else {
+        let first_len = self.capacity - start;
+        (
+          std::slice::from_raw_parts_mut(ptr.add(start), first_len),
+          std::slice::from_raw_parts_mut(ptr, len - first_len),
+        )
+      }
     }
   }
+}
 
-  fn write_index(&self) -> usize {
-    (self.read_index + self.size) % self.buffer.len()
+impl<T, S: Storage<T>> Drop for RingBuffer<T, S> {
+  fn drop(&mut self) {
+    if std::mem::needs_drop::<T>() {
+      let read = *self.read.get_mut();
+      let write = *self.write.get_mut();
+      let len = write.wrapping_sub(read);
+      let ptr = self.buffer.get_mut().storage_mut_ptr();
+      for i in 0..len {
+        let idx = (read + i) % self.capacity;
+        unsafe { ptr::drop_in_place((*ptr.add(idx)).as_mut_ptr()) };
+      }
+    }
   }
 }
 
 // Consumer impl ----------------------------
 
-pub struct RingBufferConsumer<T> {
-  buffer: Rc<UnsafeCell<RingBuffer<T>>>,
+pub struct RingBufferConsumer<T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: Arc<RingBuffer<T, S>>,
 }
 
-impl<T> Consumer<T> for RingBufferConsumer<T> {
+impl<T, S: Storage<T>> Consumer<T, S> for RingBufferConsumer<T, S> {
   fn slot_count(&self) -> usize {
-    self.inner_buffer().read_slots()
+    self.buffer.read_slots()
   }
 
-  fn pop(&mut self) -> Result<T, PopError>
-  where
-    T: Copy,
-  {
-    self.inner_buffer_mut().pop()
+  fn pop(&mut self) -> Result<T, PopError> {
+    self.buffer.pop()
   }
 
-  fn region(&mut self) -> RingBufferConsumerRegion<T> {
-    let buffer = self.buffer.clone();
-    let slices = self.inner_buffer_mut().read_slices();
+  fn region(&mut self) -> RingBufferConsumerRegion<'_, T, S> {
+    let buffer = Arc::clone(&self.buffer);
+    let slices = self.buffer_ref().read_slices();
     RingBufferConsumerRegion {
       buffer,
       slices,
       consumed: 0,
     }
   }
+
+  // The transactional counterpart to `region()`: the read cursor is only
+  // advanced by an explicit `commit`/`commit_n` on the returned region, so
+  // a caller that can't tell yet how much of what it read it's going to
+  // keep (parse-or-rewind, speculative fills) can just drop it to put
+  // everything back.
+  fn postponed_region(&mut self) -> RingBufferConsumerPostponedRegion<'_, T, S> {
+    let buffer = Arc::clone(&self.buffer);
+    let slices = self.buffer_ref().read_slices();
+    RingBufferConsumerPostponedRegion { buffer, slices }
+  }
+}
+
+impl<T, S: Storage<T>> RingBufferConsumer<T, S> {
+  // Launders the lifetime of the returned reference away from `&self` so
+  // the region snapshot can outlive the call that produced it, mirroring
+  // the raw-pointer derefs the rest of this module uses to hand out slices
+  // from the shared allocation.
+  fn buffer_ref<'a>(&self) -> &'a RingBuffer<T, S> {
+    unsafe { &*Arc::as_ptr(&self.buffer) }
+  }
 }
 
-impl<T> RingBufferConsumer<T> {
-  fn inner_buffer(&self) -> &RingBuffer<T> {
-    unsafe { &*self.buffer.get() }
+pub struct RingBufferConsumerPostponedRegion<'a, T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: Arc<RingBuffer<T, S>>,
+  slices: (&'a [T], &'a [T]),
+}
+
+impl<T, S: Storage<T>> RingBufferConsumerPostponedRegion<'_, T, S> {
+  pub fn slot_count(&self) -> usize {
+    self.slices.0.len() + self.slices.1.len()
+  }
+
+  pub fn as_slices(&self) -> (&[T], &[T]) {
+    self.slices
+  }
+
+  fn slot_ref(&self, index: usize) -> &T {
+    if index < self.slices.0.len() {
+      &self.slices.0[index]
+    } else {
+      &self.slices.1[index - self.slices.0.len()]
+    }
   }
 
-  fn inner_buffer_mut(&mut self) -> &mut RingBuffer<T> {
-    unsafe { &mut *self.buffer.get() }
+  // Commits every slot in this region to the consumer.
+  pub fn commit(self) {
+    let n = self.slot_count();
+    self.commit_n(n);
+  }
+
+  // Commits only the first `n` slots; the rest are left in the buffer,
+  // exactly as if this region had never existed, for a later region to see.
+  //
+  // `as_slices` only ever hands out shared references, so committing a slot
+  // never moves its value out of the buffer; once the read cursor passes
+  // it, nothing else will ever run its destructor, so it must be dropped
+  // here (mirrors `RingBufferProducerPostponedRegion::commit_n` dropping the
+  // *uncommitted* excess on the producer side).
+  pub fn commit_n(self, n: usize) {
+    let n = self.slot_count().min(n);
+    if std::mem::needs_drop::<T>() {
+      for i in 0..n {
+        unsafe { ptr::drop_in_place(self.slot_ref(i) as *const T as *mut T) };
+      }
+    }
+    let read = self.buffer.read.load(Ordering::Relaxed);
+    self
+      .buffer
+      .read
+      .store(read.wrapping_add(n), Ordering::Release);
+    std::mem::forget(self);
   }
 }
 
-pub struct RingBufferConsumerRegion<'a, T> {
-  buffer: Rc<UnsafeCell<RingBuffer<T>>>,
+impl<T, S: Storage<T>> Drop for RingBufferConsumerPostponedRegion<'_, T, S> {
+  // Rolling back is simply *not* advancing the read cursor: `as_slices`
+  // only ever hands out shared references, so nothing has been moved out
+  // of the buffer and every slot is exactly as valid as before the region
+  // was taken.
+  fn drop(&mut self) {}
+}
+
+pub struct RingBufferConsumerRegion<'a, T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: Arc<RingBuffer<T, S>>,
   slices: (&'a [T], &'a [T]),
   consumed: usize,
 }
 
-impl<T> Drop for RingBufferConsumerRegion<'_, T> {
+impl<T, S: Storage<T>> Drop for RingBufferConsumerRegion<'_, T, S> {
   fn drop(&mut self) {
-    let buffer = unsafe { &mut *self.buffer.get() };
-    buffer.read_index += self.consumed;
-    buffer.size -= self.consumed;
+    let read = self.buffer.read.load(Ordering::Relaxed);
+    self
+      .buffer
+      .read
+      .store(read.wrapping_add(self.consumed), Ordering::Release);
+  }
+}
+
+impl<T, S: Storage<T>> RingBufferConsumerRegion<'_, T, S> {
+  fn slot_ref(&self, index: usize) -> &T {
+    if index < self.slices.0.len() {
+      &self.slices.0[index]
+    } else {
+      &self.slices.1[index - self.slices.0.len()]
+    }
   }
 }
 
-impl<T> ConsumerRegion<'_, T> for RingBufferConsumerRegion<'_, T> {
+impl<T, S: Storage<T>> ConsumerRegion<'_, T> for RingBufferConsumerRegion<'_, T, S> {
   fn slot_count(&self) -> usize {
     self.slices.0.len() + self.slices.1.len() - self.consumed
   }
 
   // Provides next slot and advances the cursor one position within the region
-  fn pop(&mut self) -> Result<T, PopError>
-  where
-    T: Copy,
-  {
+  fn pop(&mut self) -> Result<T, PopError> {
     let capacity = self.slices.0.len() + self.slices.1.len();
     let index = self.consumed;
     if index >= capacity {
       Err(PopError)
     } else {
       self.consumed += 1;
-      if index < self.slices.0.len() {
-        Ok(self.slices.0[index])
-      } else {
-        Ok(self.slices.1[index - self.slices.0.len()])
-      }
+      // Moves the value out of the region's borrow; sound because this slot
+      // is never read again, here or in the consumer, once `consumed` has
+      // passed it.
+      Ok(unsafe { ptr::read(self.slot_ref(index)) })
     }
   }
 
@@ -178,8 +429,17 @@ impl<T> ConsumerRegion<'_, T> for RingBufferConsumerRegion<'_, T> {
     }
   }
 
+  // Slots skipped this way are discarded rather than handed to the caller,
+  // so they must be dropped here or they would never be dropped at all:
+  // once `consumed` passes them the consumer's cursor moves past them too,
+  // and a later `push` would overwrite them without running their
+  // destructor.
   fn advance(&mut self, n: usize) {
-    self.consumed += self.slot_count().min(n);
+    let n = self.slot_count().min(n);
+    for i in 0..n {
+      unsafe { ptr::drop_in_place(self.slot_ref(self.consumed + i) as *const T as *mut T) };
+    }
+    self.consumed += n;
   }
 }
 
@@ -187,10 +447,7 @@ impl<T> ConsumerRegion<'_, T> for RingBufferConsumerRegion<'_, T> {
 // within the region, without requiring sync within the Consumer.
 // An alternative or complement to the Iterator would be to have a pop method
 // within the region.
-impl<T> Iterator for RingBufferConsumerRegion<'_, T>
-where
-  T: Copy,
-{
+impl<T, S: Storage<T>> Iterator for RingBufferConsumerRegion<'_, T, S> {
   type Item = T;
 
   // Provides next slot and advances the cursor one position within the region
@@ -204,65 +461,143 @@ where
 
 // Producer impl --------------------------------
 
-pub struct RingBufferProducer<T> {
-  buffer: Rc<UnsafeCell<RingBuffer<T>>>,
+pub struct RingBufferProducer<T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: Arc<RingBuffer<T, S>>,
 }
 
-impl<T> Producer<T> for RingBufferProducer<T> {
+impl<T, S: Storage<T>> Producer<T, S> for RingBufferProducer<T, S> {
   fn slot_count(&self) -> usize {
-    self.inner_buffer().write_slots()
+    self.buffer.write_slots()
   }
 
-  fn push(&mut self, value: T) -> Result<(), PushError>
-  where
-    T: Copy,
-  {
-    self.inner_buffer_mut().push(value)
+  fn push(&mut self, value: T) -> Result<(), PushError> {
+    self.buffer.push(value)
   }
 
-  fn region(&mut self) -> RingBufferProducerRegion<T> {
-    let buffer = self.buffer.clone();
-    let slices = self.inner_buffer_mut().write_slices();
+  fn region(&mut self) -> RingBufferProducerRegion<'_, T, S> {
+    let buffer = Arc::clone(&self.buffer);
+    let slices = self.buffer_ref().write_slices();
     RingBufferProducerRegion {
       buffer,
       slices,
       produced: 0,
     }
   }
+
+  // See `RingBufferConsumer::postponed_region`: the write cursor is only
+  // advanced by an explicit `commit`/`commit_n`, so a batch of speculative
+  // writes can be abandoned in full just by dropping the region.
+  fn postponed_region(&mut self) -> RingBufferProducerPostponedRegion<'_, T, S> {
+    let buffer = Arc::clone(&self.buffer);
+    let slices = self.buffer_ref().write_slices();
+    RingBufferProducerPostponedRegion {
+      buffer,
+      slices,
+      produced: 0,
+    }
+  }
+}
+
+impl<T, S: Storage<T>> RingBufferProducer<T, S> {
+  // See `RingBufferConsumer::buffer_ref` for why the lifetime is laundered.
+  fn buffer_ref<'a>(&self) -> &'a RingBuffer<T, S> {
+    unsafe { &*Arc::as_ptr(&self.buffer) }
+  }
+}
+
+pub struct RingBufferProducerPostponedRegion<'a, T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: Arc<RingBuffer<T, S>>,
+  slices: (&'a mut [MaybeUninit<T>], &'a mut [MaybeUninit<T>]),
+  produced: usize,
 }
 
-impl<T> RingBufferProducer<T> {
-  fn inner_buffer(&self) -> &RingBuffer<T> {
-    unsafe { &*self.buffer.get() }
+impl<T, S: Storage<T>> RingBufferProducerPostponedRegion<'_, T, S> {
+  pub fn slot_count(&self) -> usize {
+    self.slices.0.len() + self.slices.1.len() - self.produced
+  }
+
+  // Tentatively writes a value into the next slot; it's only visible to the
+  // consumer once this region is committed.
+  pub fn push(&mut self, value: T) -> Result<(), PushError> {
+    let capacity = self.slices.0.len() + self.slices.1.len();
+    let index = self.produced;
+    if index >= capacity {
+      Err(PushError)
+    } else {
+      self.produced += 1;
+      self.slot_mut(index).write(value);
+      Ok(())
+    }
+  }
+
+  fn slot_mut(&mut self, index: usize) -> &mut MaybeUninit<T> {
+    if index < self.slices.0.len() {
+      &mut self.slices.0[index]
+    } else {
+      &mut self.slices.1[index - self.slices.0.len()]
+    }
+  }
+
+  // Commits every value pushed to this region so far.
+  pub fn commit(self) {
+    let n = self.produced;
+    self.commit_n(n);
+  }
+
+  // Commits only the first `n` pushed values; any pushed beyond that are
+  // dropped in place rather than being left to leak, or silently
+  // overwritten without running their destructor on the next push.
+  pub fn commit_n(mut self, n: usize) {
+    let n = self.produced.min(n);
+    if std::mem::needs_drop::<T>() {
+      for i in n..self.produced {
+        unsafe { ptr::drop_in_place(self.slot_mut(i).as_mut_ptr()) };
+      }
+    }
+    let write = self.buffer.write.load(Ordering::Relaxed);
+    self
+      .buffer
+      .write
+      .store(write.wrapping_add(n), Ordering::Release);
+    std::mem::forget(self);
   }
+}
 
-  fn inner_buffer_mut(&mut self) -> &mut RingBuffer<T> {
-    unsafe { &mut *self.buffer.get() }
+impl<T, S: Storage<T>> Drop for RingBufferProducerPostponedRegion<'_, T, S> {
+  // Rolling back leaves the write cursor untouched, but every value already
+  // pushed into a slot is real, live data (moved in with
+  // `MaybeUninit::write`), so it must be dropped here or it leaks.
+  fn drop(&mut self) {
+    if std::mem::needs_drop::<T>() {
+      for i in 0..self.produced {
+        unsafe { ptr::drop_in_place(self.slot_mut(i).as_mut_ptr()) };
+      }
+    }
   }
 }
 
-pub struct RingBufferProducerRegion<'a, T> {
-  buffer: Rc<UnsafeCell<RingBuffer<T>>>,
-  slices: (&'a mut [T], &'a mut [T]),
+pub struct RingBufferProducerRegion<'a, T, S: Storage<T> = Box<[MaybeUninit<T>]>> {
+  buffer: Arc<RingBuffer<T, S>>,
+  slices: (&'a mut [MaybeUninit<T>], &'a mut [MaybeUninit<T>]),
   produced: usize,
 }
 
-impl<T> Drop for RingBufferProducerRegion<'_, T> {
+impl<T, S: Storage<T>> Drop for RingBufferProducerRegion<'_, T, S> {
   fn drop(&mut self) {
-    let buffer = unsafe { &mut *self.buffer.get() };
-    buffer.size += self.produced;
+    let write = self.buffer.write.load(Ordering::Relaxed);
+    self
+      .buffer
+      .write
+      .store(write.wrapping_add(self.produced), Ordering::Release);
   }
 }
 
-impl<'a, T> ProducerRegion<'a, T> for RingBufferProducerRegion<'a, T> {
+impl<'a, T, S: Storage<T>> ProducerRegion<'a, T> for RingBufferProducerRegion<'a, T, S> {
   fn slot_count(&self) -> usize {
     self.slices.0.len() + self.slices.1.len() - self.produced
   }
 
-  fn push(&mut self, value: T) -> Result<(), PushError>
-  where
-    T: Copy,
-  {
+  fn push(&mut self, value: T) -> Result<(), PushError> {
     let capacity = self.slices.0.len() + self.slices.1.len();
     let index = self.produced;
     if index >= capacity {
@@ -270,27 +605,50 @@ impl<'a, T> ProducerRegion<'a, T> for RingBufferProducerRegion<'a, T> {
     } else {
       self.produced += 1;
       if index < self.slices.0.len() {
-        self.slices.0[index] = value;
+        self.slices.0[index].write(value);
       } else {
-        self.slices.1[index - self.slices.0.len()] = value;
+        self.slices.1[index - self.slices.0.len()].write(value);
       }
       Ok(())
     }
   }
 }
 
+#[cfg(feature = "std")]
+impl<'a> RingBufferProducerRegion<'a, u8> {
+  // Gives direct mutable access to the not-yet-produced bytes so `io::Write`
+  // can `copy_from_slice` into both wrap-around segments instead of pushing
+  // byte by byte. `u8` has no invalid bit patterns and no drop glue, so
+  // treating the uninitialized range as `[u8]` here is sound.
+  pub(crate) fn write_slices(&mut self) -> (&mut [u8], &mut [u8]) {
+    let produced = self.produced;
+    let s1_len = self.slices.0.len();
+    let (s1, s2) = if produced < s1_len {
+      (&mut self.slices.0[produced..], &mut self.slices.1[..])
+    } else {
+      (&mut self.slices.1[produced - s1_len..], &mut [][..])
+    };
+    unsafe {
+      (
+        std::slice::from_raw_parts_mut(s1.as_mut_ptr() as *mut u8, s1.len()),
+        std::slice::from_raw_parts_mut(s2.as_mut_ptr() as *mut u8, s2.len()),
+      )
+    }
+  }
+
+  pub(crate) fn advance(&mut self, n: usize) {
+    self.produced += n;
+  }
+}
+
 #[cfg(test)]
 mod tests {
-  use super::{
-    RingBuffer, RingBufferConsumer, RingBufferConsumerRegion, RingBufferProducer,
-    RingBufferProducerRegion,
-  };
+  use super::{RingBuffer, RingBufferConsumerRegion, RingBufferProducerRegion};
   use crate::{Consumer, ConsumerRegion, PopError, Producer, ProducerRegion, PushError};
   use std::cell::UnsafeCell;
-  use std::rc::Rc;
-
-  struct NonCopyType(i32);
-  type CopyType = i32;
+  use std::mem::MaybeUninit;
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::Arc;
 
   #[test]
   fn consumer_region_slot_count_for_single_buffer() {
@@ -365,7 +723,7 @@ mod tests {
 
   #[test]
   fn producer_region_slot_count_for_single_buffer() {
-    let mut s1 = [1, 2];
+    let mut s1 = uninit_slots([1, 2]);
     let mut s2 = [];
     let region = new_producer_region(&mut s1, &mut s2);
     assert_eq!(2, region.slot_count())
@@ -373,16 +731,16 @@ mod tests {
 
   #[test]
   fn producer_region_slot_count_for_split_buffer() {
-    let mut s1 = [3];
-    let mut s2 = [0, 1];
+    let mut s1 = uninit_slots([3]);
+    let mut s2 = uninit_slots([0, 1]);
     let region = new_producer_region(&mut s1, &mut s2);
     assert_eq!(3, region.slot_count());
   }
 
   #[test]
   fn producer_region_push() {
-    let mut s1 = [3];
-    let mut s2 = [0, 1];
+    let mut s1 = uninit_slots([3]);
+    let mut s2 = uninit_slots([0, 1]);
     {
       let mut region = new_producer_region(&mut s1, &mut s2);
       assert_eq!(region.push(10), Ok(()));
@@ -391,19 +749,21 @@ mod tests {
       assert_eq!(region.push(13), Err(PushError));
       assert_eq!(region.slot_count(), 0);
     }
-    assert_eq!(s1, [10]);
-    assert_eq!(s2, [11, 12]);
+    assert_eq!(unsafe { assume_init(&s1) }, [10]);
+    assert_eq!(unsafe { assume_init(&s2) }, [11, 12]);
   }
 
   const E: [i32; 0] = [];
   const V: [i32; 5] = [0, 1, 2, 3, 4];
 
-  fn new_ring_buffer(size: usize) -> Rc<UnsafeCell<RingBuffer<i32>>> {
-    Rc::new(UnsafeCell::new(RingBuffer {
-      buffer: Box::new([]),
-      read_index: 0,
-      size,
-    }))
+  fn new_ring_buffer(size: usize) -> Arc<RingBuffer<i32, Box<[MaybeUninit<i32>]>>> {
+    Arc::new(RingBuffer {
+      buffer: UnsafeCell::new(Box::new([])),
+      capacity: size,
+      read: AtomicUsize::new(0),
+      write: AtomicUsize::new(size),
+      _marker: std::marker::PhantomData,
+    })
   }
 
   fn new_consumer_region1<'a>() -> RingBufferConsumerRegion<'a, i32> {
@@ -423,8 +783,8 @@ mod tests {
   }
 
   fn new_producer_region<'a>(
-    s1: &'a mut [i32],
-    s2: &'a mut [i32],
+    s1: &'a mut [MaybeUninit<i32>],
+    s2: &'a mut [MaybeUninit<i32>],
   ) -> RingBufferProducerRegion<'a, i32> {
     RingBufferProducerRegion {
       buffer: new_ring_buffer(s1.len() + s2.len()),
@@ -432,4 +792,12 @@ mod tests {
       produced: 0,
     }
   }
+
+  fn uninit_slots<const N: usize>(values: [i32; N]) -> [MaybeUninit<i32>; N] {
+    values.map(MaybeUninit::new)
+  }
+
+  unsafe fn assume_init<const N: usize>(slots: &[MaybeUninit<i32>; N]) -> [i32; N] {
+    slots.map(|slot| slot.assume_init())
+  }
 }
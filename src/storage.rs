@@ -0,0 +1,61 @@
+use std::mem::MaybeUninit;
+
+/// Abstracts over where the ring buffer's slots live, so `RingBuffer<T, S>`
+/// can keep them in a boxed slice (the default), inline in a
+/// `[MaybeUninit<T>; N]`, or in a caller-owned borrowed slice, without
+/// `push`/`pop`/`read_slices`/`write_slices` needing to know which. Whatever
+/// `S` is, the storage value and the cursors are held in an `Arc`, so every
+/// kind of storage still needs a heap allocator.
+///
+/// # Safety
+///
+/// Implementors must return a pointer to (and the length of) a single,
+/// stable, non-overlapping run of `MaybeUninit<T>` slots that stays valid
+/// for as long as the `Storage` value is not moved.
+pub unsafe trait Storage<T> {
+  fn storage_ptr(&self) -> *const MaybeUninit<T>;
+  fn storage_mut_ptr(&mut self) -> *mut MaybeUninit<T>;
+  fn storage_len(&self) -> usize;
+}
+
+unsafe impl<T> Storage<T> for Box<[MaybeUninit<T>]> {
+  fn storage_ptr(&self) -> *const MaybeUninit<T> {
+    self.as_ptr()
+  }
+
+  fn storage_mut_ptr(&mut self) -> *mut MaybeUninit<T> {
+    self.as_mut_ptr()
+  }
+
+  fn storage_len(&self) -> usize {
+    self.len()
+  }
+}
+
+unsafe impl<T, const N: usize> Storage<T> for [MaybeUninit<T>; N] {
+  fn storage_ptr(&self) -> *const MaybeUninit<T> {
+    self.as_ptr()
+  }
+
+  fn storage_mut_ptr(&mut self) -> *mut MaybeUninit<T> {
+    self.as_mut_ptr()
+  }
+
+  fn storage_len(&self) -> usize {
+    N
+  }
+}
+
+unsafe impl<T> Storage<T> for &mut [MaybeUninit<T>] {
+  fn storage_ptr(&self) -> *const MaybeUninit<T> {
+    <[MaybeUninit<T>]>::as_ptr(self)
+  }
+
+  fn storage_mut_ptr(&mut self) -> *mut MaybeUninit<T> {
+    <[MaybeUninit<T>]>::as_mut_ptr(self)
+  }
+
+  fn storage_len(&self) -> usize {
+    <[MaybeUninit<T>]>::len(self)
+  }
+}